@@ -0,0 +1,154 @@
+use std::fs;
+
+use anyhow::Result;
+
+use crate::{Test, TestCategory, TestResult};
+
+pub struct CapabilitiesTest {}
+
+#[derive(Default)]
+pub struct CapabilitiesResult {
+    pub effective: Vec<String>,
+    pub permitted: Vec<String>,
+    pub inheritable: Vec<String>,
+    pub bounding_only: Vec<String>,
+    pub escalation_effective: Vec<String>,
+    pub escalation_inheritable: Vec<String>,
+}
+
+/// Well-known capability numbers, as defined in `linux/capability.h`. Only
+/// the ones relevant to container breakout are named here, and
+/// `decode_capset` reports only these; other set bits are not reported.
+const KNOWN_CAPABILITIES: &[(u32, &str)] = &[
+    (2, "CAP_DAC_READ_SEARCH"),
+    (12, "CAP_NET_ADMIN"),
+    (16, "CAP_SYS_MODULE"),
+    (17, "CAP_SYS_RAWIO"),
+    (19, "CAP_SYS_PTRACE"),
+    (21, "CAP_SYS_ADMIN"),
+    (22, "CAP_SYS_BOOT"),
+    (27, "CAP_MKNOD"),
+];
+
+/// Capabilities that alone are enough to escalate out of a container.
+const ESCALATION_CAPABILITIES: &[&str] = &["CAP_SYS_ADMIN", "CAP_SYS_MODULE"];
+
+fn capability_name(bit: u32) -> String {
+    KNOWN_CAPABILITIES
+        .iter()
+        .find(|(n, _)| *n == bit)
+        .map(|(_, name)| name.to_string())
+        .unwrap_or_else(|| format!("CAP_{}", bit))
+}
+
+fn decode_capset(hex_mask: &str) -> Vec<String> {
+    let mask = u64::from_str_radix(hex_mask, 16).unwrap_or(0);
+    KNOWN_CAPABILITIES
+        .iter()
+        .filter(|(bit, _)| mask & (1u64 << bit) != 0)
+        .map(|(bit, _)| capability_name(*bit))
+        .collect()
+}
+
+fn status_field<'a>(status: &'a str, field: &str) -> Option<&'a str> {
+    status
+        .lines()
+        .find(|line| line.starts_with(field))
+        .and_then(|line| line.split(':').nth(1))
+        .map(|value| value.trim())
+}
+
+impl Test for CapabilitiesTest {
+    fn name(&self) -> String {
+        "process capabilities".to_string()
+    }
+
+    fn run(&self) -> Result<Box<dyn TestResult>, ()> {
+        let mut result = CapabilitiesResult::default();
+
+        if let Ok(status) = fs::read_to_string("/proc/self/status") {
+            let inh = status_field(&status, "CapInh").map(decode_capset).unwrap_or_default();
+            let prm = status_field(&status, "CapPrm").map(decode_capset).unwrap_or_default();
+            let eff = status_field(&status, "CapEff").map(decode_capset).unwrap_or_default();
+            let bnd = status_field(&status, "CapBnd").map(decode_capset).unwrap_or_default();
+
+            result.bounding_only = bnd.iter().filter(|cap| !eff.contains(cap)).cloned().collect();
+            result.escalation_effective = eff
+                .iter()
+                .filter(|cap| ESCALATION_CAPABILITIES.contains(&cap.as_str()))
+                .cloned()
+                .collect();
+            // Inheritable escalation capabilities don't grant anything by
+            // themselves, but let a binary with matching file capabilities
+            // pick them back up across exec - worth flagging separately.
+            result.escalation_inheritable = inh
+                .iter()
+                .filter(|cap| ESCALATION_CAPABILITIES.contains(&cap.as_str()))
+                .cloned()
+                .collect();
+            result.inheritable = inh;
+            result.permitted = prm;
+            result.effective = eff;
+        }
+
+        Ok(Box::new(result))
+    }
+
+    fn category(&self) -> TestCategory {
+        TestCategory::High
+    }
+}
+
+impl TestResult for CapabilitiesResult {
+    fn success(&self) -> bool {
+        self.escalation_effective.is_empty()
+    }
+
+    fn explain(&self) -> String {
+        let inheritable_note = if !self.escalation_inheritable.is_empty() {
+            format!(
+                "; escalation-enabling capabilities are inheritable (pickupable via file capabilities on exec): {}",
+                self.escalation_inheritable.join(", ")
+            )
+        } else {
+            String::new()
+        };
+
+        if !self.escalation_effective.is_empty() {
+            return format!(
+                "escalation-enabling capabilities are effective: {} (effective set: {}; permitted: {}; inheritable: {}; bounding-only: {}){}",
+                self.escalation_effective.join(", "),
+                self.effective.join(", "),
+                self.permitted.join(", "),
+                self.inheritable.join(", "),
+                self.bounding_only.join(", "),
+                inheritable_note
+            );
+        }
+
+        if self.effective.is_empty() && self.permitted.is_empty() && self.inheritable.is_empty() && self.bounding_only.is_empty() {
+            "no notable capabilities in the effective, permitted, inheritable, or bounding set".to_string()
+        } else {
+            format!(
+                "no escalation-enabling capabilities effective (effective set: {}; permitted: {}; inheritable: {}; bounding-only: {}){}",
+                self.effective.join(", "),
+                self.permitted.join(", "),
+                self.inheritable.join(", "),
+                self.bounding_only.join(", "),
+                inheritable_note
+            )
+        }
+    }
+
+    fn as_string(&self) -> String {
+        if self.success() {
+            "no".to_string()
+        } else {
+            "yes".to_string()
+        }
+    }
+
+    fn fault_code(&self) -> String {
+        "AII3300".to_string()
+    }
+}
@@ -4,6 +4,7 @@ use std::os::unix::fs::FileTypeExt;
 
 use anyhow::Result;
 
+use crate::userns::{detect_user_namespace_mapping, UserNamespaceMapping};
 use crate::{Test, TestCategory, TestResult};
 
 pub struct DeviceAccessTest {}
@@ -100,7 +101,13 @@ impl Test for DeviceAccessTest {
     }
 
     fn category(&self) -> TestCategory {
-        TestCategory::High
+        // Device nodes owned by host-root are not directly accessible to a
+        // remapped container root, so downgrade the severity when a user
+        // namespace with non-identity id mapping is active.
+        match detect_user_namespace_mapping() {
+            UserNamespaceMapping::Remapped => TestCategory::Medium,
+            UserNamespaceMapping::Identity | UserNamespaceMapping::Absent => TestCategory::High,
+        }
     }
 }
 
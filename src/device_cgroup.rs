@@ -0,0 +1,195 @@
+use std::fs;
+use std::os::unix::fs::{FileTypeExt, MetadataExt};
+use std::path::Path;
+
+use anyhow::Result;
+
+use crate::{Test, TestCategory, TestResult};
+
+pub struct DeviceCgroupTest {}
+
+#[derive(Default)]
+pub struct DeviceCgroupResult {
+    pub cgroup_v1: bool,
+    pub cgroup_v2: bool,
+    pub unconfined: bool,
+    pub allow_rules: Vec<String>,
+    pub permitted_dangerous_devices: Vec<String>,
+}
+
+/// A single parsed row of cgroup v1 `devices.list`: `TYPE MAJOR:MINOR ACCESS`.
+struct DeviceRule {
+    device_type: char,
+    major: String,
+    minor: String,
+    access: String,
+}
+
+impl DeviceRule {
+    fn matches(&self, major: u32, minor: u32) -> bool {
+        let major_matches = self.major == "*" || self.major.parse::<u32>().ok() == Some(major);
+        let minor_matches = self.minor == "*" || self.minor.parse::<u32>().ok() == Some(minor);
+        major_matches && minor_matches
+    }
+
+    /// Whether this rule grants actual data access, as opposed to a
+    /// mknod-only (`m`) rule. Docker/containerd's default cgroup v1 policy
+    /// always includes `c *:* m` and `b *:* m`, which permit creating device
+    /// nodes but not reading or writing through them.
+    fn grants_read_or_write(&self) -> bool {
+        self.access.contains('r') || self.access.contains('w')
+    }
+
+    fn grants_full_access(&self) -> bool {
+        self.access.contains('r') && self.access.contains('w')
+    }
+}
+
+fn parse_device_rule(line: &str) -> Option<DeviceRule> {
+    let parts: Vec<&str> = line.split_whitespace().collect();
+    if parts.len() != 3 {
+        return None;
+    }
+    let device_type = parts[0].chars().next()?;
+    let (major, minor) = parts[1].split_once(':')?;
+    Some(DeviceRule {
+        device_type,
+        major: major.to_string(),
+        minor: minor.to_string(),
+        access: parts[2].to_string(),
+    })
+}
+
+/// Device nodes whose presence is a concern regardless of visibility; only
+/// reported here when the cgroup policy actually permits access to them.
+const DANGEROUS_NODES: &[&str] = &["/dev/mem", "/dev/kmem", "/dev/port"];
+
+impl Test for DeviceCgroupTest {
+    fn name(&self) -> String {
+        "cgroup device controller policy".to_string()
+    }
+
+    fn run(&self) -> Result<Box<dyn TestResult>, ()> {
+        let mut result = DeviceCgroupResult::default();
+
+        let devices_list = Path::new("/sys/fs/cgroup/devices/devices.list");
+        if devices_list.exists() {
+            result.cgroup_v1 = true;
+
+            let mut rules = Vec::new();
+            if let Ok(content) = fs::read_to_string(devices_list) {
+                for line in content.lines() {
+                    if let Some(rule) = parse_device_rule(line) {
+                        if rule.device_type == 'a' && rule.major == "*" && rule.minor == "*" && rule.grants_full_access() {
+                            result.unconfined = true;
+                        }
+                        result
+                            .allow_rules
+                            .push(format!("{} {}:{} {}", rule.device_type, rule.major, rule.minor, rule.access));
+                        rules.push(rule);
+                    }
+                }
+            }
+
+            // Correlate dangerous/visible device nodes with what the policy
+            // actually permits, rather than just reporting that they exist.
+            let is_permitted = |device_type: char, major: u32, minor: u32| {
+                rules.iter().any(|r| {
+                    (r.device_type == 'a' || r.device_type == device_type)
+                        && r.matches(major, minor)
+                        && r.grants_read_or_write()
+                })
+            };
+
+            for node in DANGEROUS_NODES {
+                if let Ok(metadata) = fs::metadata(node) {
+                    let rdev = metadata.rdev();
+                    let major = (rdev >> 8) as u32 & 0xfff;
+                    let minor = (rdev & 0xff) as u32 | ((rdev >> 12) as u32 & !0xff);
+                    if is_permitted('c', major, minor) {
+                        result.permitted_dangerous_devices.push(node.to_string());
+                    }
+                }
+            }
+
+            if let Ok(entries) = fs::read_dir("/dev") {
+                for entry in entries.flatten() {
+                    let path = entry.path();
+                    let Ok(metadata) = entry.metadata() else {
+                        continue;
+                    };
+                    if !metadata.file_type().is_block_device() {
+                        continue;
+                    }
+                    let rdev = metadata.rdev();
+                    let major = (rdev >> 8) as u32 & 0xfff;
+                    let minor = (rdev & 0xff) as u32 | ((rdev >> 12) as u32 & !0xff);
+                    if is_permitted('b', major, minor) {
+                        result
+                            .permitted_dangerous_devices
+                            .push(path.to_string_lossy().to_string());
+                    }
+                }
+            }
+        } else if Path::new("/sys/fs/cgroup/cgroup.controllers").exists() {
+            // cgroup v2: the device controller is enforced via an eBPF
+            // program attached to the cgroup rather than a regular
+            // controller, so it never appears in cgroup.controllers. We can
+            // confirm we're on a v2 hierarchy, but not whether a device
+            // program is actually attached - that has to be reported as
+            // unconfirmed rather than asserted.
+            result.cgroup_v2 = true;
+        }
+
+        result.permitted_dangerous_devices.sort();
+        result.permitted_dangerous_devices.dedup();
+
+        Ok(Box::new(result))
+    }
+
+    fn category(&self) -> TestCategory {
+        TestCategory::High
+    }
+}
+
+impl TestResult for DeviceCgroupResult {
+    fn success(&self) -> bool {
+        !self.unconfined && self.permitted_dangerous_devices.is_empty()
+    }
+
+    fn explain(&self) -> String {
+        if self.cgroup_v1 {
+            if self.unconfined {
+                return "cgroup v1 device controller allows \"a *:* rwm\" - container is unconfined".to_string();
+            }
+            if !self.permitted_dangerous_devices.is_empty() {
+                return format!(
+                    "cgroup v1 policy permits access to dangerous device nodes: {}",
+                    self.permitted_dangerous_devices.join(", ")
+                );
+            }
+            return format!(
+                "cgroup v1 device controller restricts access ({} allow rules, no dangerous nodes permitted)",
+                self.allow_rules.len()
+            );
+        }
+
+        if self.cgroup_v2 {
+            return "cgroup v2 detected - the device controller is BPF-based and its attachment cannot be confirmed from userspace; allow-list is opaque whether present or absent".to_string();
+        }
+
+        "no cgroup device controller detected".to_string()
+    }
+
+    fn as_string(&self) -> String {
+        if self.success() {
+            "no".to_string()
+        } else {
+            "yes".to_string()
+        }
+    }
+
+    fn fault_code(&self) -> String {
+        "AII3150".to_string()
+    }
+}
@@ -4,6 +4,7 @@ use std::path::Path;
 
 use anyhow::Result;
 
+use crate::userns::{detect_user_namespace_mapping, UserNamespaceMapping};
 use crate::{Test, TestCategory, TestResult};
 
 pub struct HostMountsTest {}
@@ -14,6 +15,65 @@ pub struct HostMountsResult {
     pub writable_host_mounts: Vec<String>,
     pub socket_mounts: Vec<String>,
     pub host_root_mounts: Vec<String>,
+    pub shared_mounts: Vec<String>,
+}
+
+/// A single parsed row of `/proc/self/mountinfo`.
+///
+/// See `man 5 proc` for the field layout:
+/// `mount ID, parent ID, major:minor, root, mount point, mount options,
+/// optional fields..., "-", fs type, mount source, super options`.
+struct MountInfoEntry {
+    mount_point: String,
+    fs_type: String,
+    mount_source: String,
+    mount_options: String,
+    super_options: String,
+    propagation: Option<String>,
+}
+
+fn has_ro_option(options: &str) -> bool {
+    options.split(',').any(|opt| opt == "ro")
+}
+
+impl MountInfoEntry {
+    fn is_writable(&self) -> bool {
+        !has_ro_option(&self.mount_options) && !has_ro_option(&self.super_options)
+    }
+}
+
+fn parse_mountinfo_line(line: &str) -> Option<MountInfoEntry> {
+    let fields: Vec<&str> = line.split_whitespace().collect();
+    // Fixed fields 0..=5 are: mount ID, parent ID, major:minor, root, mount
+    // point, mount options. Fields after that are zero or more optional
+    // propagation tags, terminated by a literal "-".
+    if fields.len() < 6 {
+        return None;
+    }
+
+    let mount_point = fields[4].to_string();
+    let mount_options = fields[5].to_string();
+
+    let separator_index = fields.iter().skip(6).position(|f| *f == "-")? + 6;
+    let optional_fields = &fields[6..separator_index];
+    let trailing = &fields[separator_index + 1..];
+    if trailing.len() < 3 {
+        return None;
+    }
+
+    let propagation = optional_fields
+        .iter()
+        .find(|f| f.starts_with("shared:") || f.starts_with("master:"))
+        .map(|f| f.to_string());
+
+    Some(MountInfoEntry {
+        mount_point,
+        fs_type: trailing[0].to_string(),
+        mount_source: trailing[1].to_string(),
+        mount_options,
+        super_options: trailing[2].to_string(),
+        propagation,
+    })
 }
 
 impl Test for HostMountsTest {
@@ -24,86 +84,94 @@ impl Test for HostMountsTest {
     fn run(&self) -> Result<Box<dyn TestResult>, ()> {
         let mut result = HostMountsResult::default();
 
-        // Read /proc/mounts to analyze mounted filesystems
-        if let Ok(mounts_content) = fs::read_to_string("/proc/mounts") {
-            for line in mounts_content.lines() {
-                let parts: Vec<&str> = line.split_whitespace().collect();
-                if parts.len() >= 4 {
-                    let device = parts[0];
-                    let mount_point = parts[1];
-                    let fs_type = parts[2];
-                    let options = parts[3];
-
-                    // Check for dangerous mount points
-                    let dangerous_paths = [
-                        "/",
-                        "/etc",
-                        "/boot",
-                        "/var/run",
-                        "/sys",
-                        "/proc",
-                        "/var/lib/docker",
-                        "/var/lib/containerd",
-                        "/run",
-                        "/usr",
-                        "/lib",
-                        "/bin",
-                        "/sbin",
-                        "/opt",
-                        "/home",
-                    ];
-
-                    for dangerous_path in &dangerous_paths {
-                        if mount_point == *dangerous_path {
-                            result
-                                .dangerous_mounts
-                                .push(format!("{} -> {}", device, mount_point));
-                        }
-                    }
+        // Parse /proc/self/mountinfo rather than /proc/mounts: it gives us
+        // per-mount options, superblock options, and propagation tags, none
+        // of which /proc/mounts exposes.
+        if let Ok(mountinfo_content) = fs::read_to_string("/proc/self/mountinfo") {
+            for line in mountinfo_content.lines() {
+                let Some(entry) = parse_mountinfo_line(line) else {
+                    continue;
+                };
+                let device = entry.mount_source.as_str();
+                let mount_point = entry.mount_point.as_str();
+                let fs_type = entry.fs_type.as_str();
+
+                // Check for dangerous mount points
+                let dangerous_paths = [
+                    "/",
+                    "/etc",
+                    "/boot",
+                    "/var/run",
+                    "/sys",
+                    "/proc",
+                    "/var/lib/docker",
+                    "/var/lib/containerd",
+                    "/run",
+                    "/usr",
+                    "/lib",
+                    "/bin",
+                    "/sbin",
+                    "/opt",
+                    "/home",
+                ];
 
-                    // Check for host root filesystem mounts (common indicators)
-                    if device.starts_with("/dev/")
-                        && (fs_type == "ext4"
-                            || fs_type == "xfs"
-                            || fs_type == "btrfs"
-                            || fs_type == "zfs")
-                        && (mount_point == "/" || mount_point.starts_with("/host"))
-                    {
+                for dangerous_path in &dangerous_paths {
+                    if mount_point == *dangerous_path {
                         result
-                            .host_root_mounts
-                            .push(format!("{} -> {} ({})", device, mount_point, fs_type));
+                            .dangerous_mounts
+                            .push(format!("{} -> {}", device, mount_point));
                     }
+                }
 
-                    // Check for writable mounts that could be host directories
-                    if !options.contains("ro")
-                        && (mount_point.starts_with("/host")
-                            || mount_point.starts_with("/mnt")
-                            || mount_point.starts_with("/media")
-                            || (device.starts_with("/")
-                                && !device.starts_with("/dev/")
-                                && Path::new(device).exists()))
-                    {
+                // Check for host root filesystem mounts (common indicators)
+                if device.starts_with("/dev/")
+                    && (fs_type == "ext4" || fs_type == "xfs" || fs_type == "btrfs" || fs_type == "zfs")
+                    && (mount_point == "/" || mount_point.starts_with("/host"))
+                {
+                    result
+                        .host_root_mounts
+                        .push(format!("{} -> {} ({})", device, mount_point, fs_type));
+                }
+
+                // Check for writable mounts that could be host directories.
+                // A mount is only truly writable when neither the per-mount
+                // nor the superblock options carry "ro".
+                let host_backed = mount_point.starts_with("/host")
+                    || mount_point.starts_with("/mnt")
+                    || mount_point.starts_with("/media")
+                    || (device.starts_with("/") && !device.starts_with("/dev/") && Path::new(device).exists());
+
+                if entry.is_writable() && host_backed {
+                    result
+                        .writable_host_mounts
+                        .push(format!("{} -> {} (writable)", device, mount_point));
+                }
+
+                // A host-backed mount with shared or master propagation can
+                // leak mount/unmount events back to the host namespace.
+                if host_backed {
+                    if let Some(tag) = &entry.propagation {
                         result
-                            .writable_host_mounts
-                            .push(format!("{} -> {} (writable)", device, mount_point));
+                            .shared_mounts
+                            .push(format!("{} -> {} ({})", device, mount_point, tag));
                     }
+                }
 
-                    // Check for container runtime socket mounts
-                    let socket_patterns = [
-                        "docker.sock",
-                        "containerd.sock",
-                        "crio.sock",
-                        "podman.sock",
-                        "lxd/unix.socket",
-                        "kubelet",
-                    ];
-
-                    for pattern in &socket_patterns {
-                        if device.contains(pattern) || mount_point.contains(pattern) {
-                            result
-                                .socket_mounts
-                                .push(format!("{} -> {}", device, mount_point));
-                        }
+                // Check for container runtime socket mounts
+                let socket_patterns = [
+                    "docker.sock",
+                    "containerd.sock",
+                    "crio.sock",
+                    "podman.sock",
+                    "lxd/unix.socket",
+                    "kubelet",
+                ];
+
+                for pattern in &socket_patterns {
+                    if device.contains(pattern) || mount_point.contains(pattern) {
+                        result
+                            .socket_mounts
+                            .push(format!("{} -> {}", device, mount_point));
                     }
                 }
             }
@@ -140,12 +208,20 @@ impl Test for HostMountsTest {
         result.socket_mounts.dedup();
         result.host_root_mounts.sort();
         result.host_root_mounts.dedup();
+        result.shared_mounts.sort();
+        result.shared_mounts.dedup();
 
         Ok(Box::new(result))
     }
 
     fn category(&self) -> TestCategory {
-        TestCategory::High
+        // A writable host bind mount owned by host-root is not directly
+        // writable by a remapped container root, so downgrade the severity
+        // when a user namespace with non-identity id mapping is active.
+        match detect_user_namespace_mapping() {
+            UserNamespaceMapping::Remapped => TestCategory::Medium,
+            UserNamespaceMapping::Identity | UserNamespaceMapping::Absent => TestCategory::High,
+        }
     }
 }
 
@@ -155,6 +231,7 @@ impl TestResult for HostMountsResult {
             && self.socket_mounts.is_empty()
             && self.host_root_mounts.is_empty()
             && self.writable_host_mounts.is_empty()
+            && self.shared_mounts.is_empty()
     }
 
     fn explain(&self) -> String {
@@ -193,6 +270,18 @@ impl TestResult for HostMountsResult {
             ));
         }
 
+        if !self.shared_mounts.is_empty() {
+            issues.push(format!(
+                "host-backed mounts with shared propagation (mount/unmount events leak to host): {}",
+                self.shared_mounts
+                    .iter()
+                    .take(3)
+                    .cloned()
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
+
         if issues.is_empty() {
             "container filesystem isolation is secure".to_string()
         } else {
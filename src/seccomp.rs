@@ -0,0 +1,115 @@
+use std::fs;
+
+use anyhow::Result;
+
+use crate::{Test, TestCategory, TestResult};
+
+pub struct SeccompTest {}
+
+#[derive(Default)]
+pub struct SeccompResult {
+    pub mode: u32,
+    pub filter_count: Option<u32>,
+    pub no_new_privs: bool,
+}
+
+fn status_field<'a>(status: &'a str, field: &str) -> Option<&'a str> {
+    status
+        .lines()
+        .find(|line| line.starts_with(field))
+        .and_then(|line| line.split(':').nth(1))
+        .map(|value| value.trim())
+}
+
+/// Reads just the `Seccomp` mode and `NoNewPrivs` flag, shared between
+/// `run()` and `category()` so the reported severity matches the result.
+fn read_seccomp_state() -> (u32, bool) {
+    let Ok(status) = fs::read_to_string("/proc/self/status") else {
+        return (0, false);
+    };
+
+    let mode = status_field(&status, "Seccomp").and_then(|v| v.parse().ok()).unwrap_or(0);
+    let no_new_privs = status_field(&status, "NoNewPrivs")
+        .and_then(|v| v.parse::<u32>().ok())
+        .unwrap_or(0)
+        != 0;
+
+    (mode, no_new_privs)
+}
+
+impl Test for SeccompTest {
+    fn name(&self) -> String {
+        "seccomp filtering".to_string()
+    }
+
+    fn run(&self) -> Result<Box<dyn TestResult>, ()> {
+        let mut result = SeccompResult::default();
+
+        let (mode, no_new_privs) = read_seccomp_state();
+        result.mode = mode;
+        result.no_new_privs = no_new_privs;
+        if let Ok(status) = fs::read_to_string("/proc/self/status") {
+            result.filter_count = status_field(&status, "Seccomp_filters").and_then(|v| v.parse().ok());
+        }
+
+        Ok(Box::new(result))
+    }
+
+    fn category(&self) -> TestCategory {
+        // No filtering at all is a hard failure; a filter that's merely
+        // missing NoNewPrivs is a weaker, lower-severity warning.
+        let (mode, no_new_privs) = read_seccomp_state();
+        if mode == 0 {
+            TestCategory::High
+        } else if !no_new_privs {
+            TestCategory::Medium
+        } else {
+            TestCategory::High
+        }
+    }
+}
+
+impl TestResult for SeccompResult {
+    fn success(&self) -> bool {
+        self.mode != 0 && self.no_new_privs
+    }
+
+    fn explain(&self) -> String {
+        let mode_name = match self.mode {
+            0 => "disabled",
+            1 => "strict",
+            2 => "filter",
+            _ => "unknown",
+        };
+
+        let filters = self
+            .filter_count
+            .map(|n| format!("{} filter(s) attached", n))
+            .unwrap_or_else(|| "filter count unavailable".to_string());
+
+        if self.mode == 0 {
+            return format!("seccomp is disabled ({})", filters);
+        }
+
+        if !self.no_new_privs {
+            return format!(
+                "seccomp mode is {} ({}) but NoNewPrivs is not set, so a setuid binary can still escape the intended restrictions",
+                mode_name, filters
+            );
+        }
+
+        format!("seccomp mode is {} ({}), NoNewPrivs is set", mode_name, filters)
+    }
+
+    fn as_string(&self) -> String {
+        if self.success() {
+            "no".to_string()
+        } else {
+            "yes".to_string()
+        }
+    }
+
+    fn fault_code(&self) -> String {
+        "AII3400".to_string()
+    }
+}
@@ -0,0 +1,83 @@
+use std::fs;
+
+/// The state of uid/gid remapping observed via `/proc/self/uid_map` and
+/// `/proc/self/gid_map`. Findings that assume host-root privileges (a
+/// writable host bind mount, a visible device node) are far less exploitable
+/// when the container's root is actually a remapped, unprivileged host uid.
+#[derive(Debug, PartialEq, Eq)]
+pub enum UserNamespaceMapping {
+    /// `0 0 <count>` - container root is host root. No isolation from id
+    /// remapping.
+    Identity,
+    /// Inside uid/gid 0 maps to a non-zero outside id. A userns is active
+    /// and root is genuinely unprivileged on the host.
+    Remapped,
+    /// The map file was empty or unreadable, or its first line was not a
+    /// full identity or remapped-root mapping, so no namespace remapping
+    /// could be confirmed.
+    Absent,
+}
+
+/// Parses a single `/proc/[pid]/uid_map` or `gid_map` line: `inside_id
+/// outside_id count`. Only the first line is consulted, matching how the
+/// kernel reports a single-entry mapping for the common case.
+fn parse_id_map_line(line: &str) -> Option<(u32, u32, u32)> {
+    let fields: Vec<&str> = line.split_whitespace().collect();
+    if fields.len() != 3 {
+        return None;
+    }
+    Some((fields[0].parse().ok()?, fields[1].parse().ok()?, fields[2].parse().ok()?))
+}
+
+/// The full 32-bit id range, as the kernel writes it for an unmodified
+/// `0 0 4294967295` identity mapping (no user namespace in effect).
+const FULL_ID_RANGE: u32 = u32::MAX;
+
+fn detect_mapping(path: &str) -> UserNamespaceMapping {
+    let Ok(content) = fs::read_to_string(path) else {
+        return UserNamespaceMapping::Absent;
+    };
+
+    let Some(first_line) = content.lines().next() else {
+        return UserNamespaceMapping::Absent;
+    };
+
+    let Some((inside_id, outside_id, count)) = parse_id_map_line(first_line) else {
+        return UserNamespaceMapping::Absent;
+    };
+
+    if inside_id == 0 && outside_id == 0 && count == FULL_ID_RANGE {
+        UserNamespaceMapping::Identity
+    } else if inside_id == 0 && outside_id != 0 {
+        UserNamespaceMapping::Remapped
+    } else {
+        // Some other partial or unusual mapping shape: not confidently
+        // identity nor confidently remapped.
+        UserNamespaceMapping::Absent
+    }
+}
+
+/// Ranks how conservative each state is: `Identity` (no isolation) is the
+/// most conservative, `Absent` (unconfirmed) next, `Remapped` (isolated)
+/// least conservative. Used to combine uid/gid results below.
+fn conservatism_rank(mapping: &UserNamespaceMapping) -> u8 {
+    match mapping {
+        UserNamespaceMapping::Identity => 0,
+        UserNamespaceMapping::Absent => 1,
+        UserNamespaceMapping::Remapped => 2,
+    }
+}
+
+/// Combines the uid and gid map state: a container is only meaningfully
+/// remapped when both are remapped, so the weaker of the two states wins -
+/// identity beats absent, and absent beats remapped.
+pub fn detect_user_namespace_mapping() -> UserNamespaceMapping {
+    let uid_mapping = detect_mapping("/proc/self/uid_map");
+    let gid_mapping = detect_mapping("/proc/self/gid_map");
+
+    if conservatism_rank(&uid_mapping) <= conservatism_rank(&gid_mapping) {
+        uid_mapping
+    } else {
+        gid_mapping
+    }
+}